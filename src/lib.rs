@@ -50,12 +50,17 @@
 mod loader;
 
 use std::collections::HashMap;
+use std::time::Duration;
 
 use bevy_app::prelude::*;
-use bevy_asset::{AddAsset, Handle};
-use bevy_pbr::prelude::StandardMaterial;
+use bevy_asset::{AddAsset, Assets, Handle};
+use bevy_ecs::prelude::{Component, Query, Res, ResMut};
+use bevy_math::Mat4;
+use bevy_pbr::prelude::{AlphaMode, StandardMaterial};
 use bevy_reflect::TypeUuid;
 use bevy_render::{mesh::Mesh, prelude::Texture};
+use bevy_time::{Time, Timer};
+use bevy_transform::prelude::Transform;
 
 pub use loader::*;
 
@@ -70,7 +75,11 @@ impl Plugin for EqAssetsPlugin {
             .add_asset::<EqWld>()
             .add_asset::<EqNode>()
             .add_asset::<EqPrimitive>()
-            .add_asset::<EqMesh>();
+            .add_asset::<EqMesh>()
+            .add_asset::<EqSkeleton>()
+            .add_asset::<EqAnimation>()
+            .add_asset::<EqAnimatedMaterial>()
+            .add_system(animate_eq_textures.system());
     }
 }
 
@@ -88,6 +97,14 @@ pub struct EqWld {
     pub named_meshes: HashMap<String, Handle<EqMesh>>,
     pub materials: Vec<Handle<StandardMaterial>>,
     pub named_materials: HashMap<String, Handle<StandardMaterial>>,
+    /// The root of the zone's placement hierarchy, for code that wants to
+    /// walk object/region placements directly instead of spawning the scene.
+    pub root: EqNode,
+    /// The bone hierarchy for character/monster WLDs, if one was present.
+    pub skeleton: Option<Handle<EqSkeleton>>,
+    /// Named animation clips (walk, run, idle…) keyed by their 3-letter
+    /// EQ animation code, e.g. `"WAL"`, `"RUN"`, `"IDL"`.
+    pub animations: HashMap<String, Handle<EqAnimation>>,
 }
 
 #[derive(Debug, Clone, TypeUuid)]
@@ -109,4 +126,89 @@ pub struct EqMesh {
 pub struct EqPrimitive {
     pub mesh: Handle<Mesh>,
     pub material: Handle<StandardMaterial>,
+    /// The alpha mode resolved from the WLD material's render/shader flags
+    /// (masked foliage, additive/translucent water, etc).
+    pub alpha_mode: AlphaMode,
+    /// Set when the material's texture is an animated set (scrolling water,
+    /// waterfalls, lava…) rather than a single bitmap.
+    pub animated_material: Option<Handle<EqAnimatedMaterial>>,
+}
+
+#[derive(Debug, Clone, TypeUuid)]
+#[uuid = "d3f2c4c1-8b34-4c79-9f5f-6a6c0e9c2b3a"]
+pub struct EqSkeleton {
+    pub bones: Vec<EqBone>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EqBone {
+    pub name: String,
+    pub parent: Option<usize>,
+    pub local_transform: Transform,
+    /// Transforms a vertex from bind pose into this bone's local space.
+    pub inverse_bind_matrix: Mat4,
+}
+
+#[derive(Debug, Clone, TypeUuid)]
+#[uuid = "7a6a9e3a-3c0e-4a3b-8a36-d9e2c7d5b8b1"]
+pub struct EqAnimation {
+    /// The 3-letter EQ animation code, e.g. `"WAL"`, `"RUN"`, `"IDL"`.
+    pub code: String,
+    pub frame_delay_ms: u32,
+    pub tracks: Vec<EqBoneTrack>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EqBoneTrack {
+    pub bone: usize,
+    pub frames: Vec<Transform>,
+}
+
+/// The frames of an animated texture set (scrolling water, waterfalls,
+/// lava…) and the delay between them. Attach an [`EqAnimatedTexture`]
+/// component to drive the swap automatically, or read `frames` directly to
+/// drive it yourself.
+#[derive(Debug, Clone, TypeUuid)]
+#[uuid = "b8d4a8ce-2c2f-4f1a-9e0e-3f6b5a8c1d2e"]
+pub struct EqAnimatedMaterial {
+    pub frames: Vec<Handle<Texture>>,
+    pub frame_delay_ms: u32,
+}
+
+/// Cycles `material`'s base color texture through `animated_material`'s
+/// frames on a timer. Spawned automatically alongside any `EqPrimitive`
+/// whose material is an animated texture set.
+#[derive(Component, Debug, Clone)]
+pub struct EqAnimatedTexture {
+    pub material: Handle<StandardMaterial>,
+    pub animated_material: Handle<EqAnimatedMaterial>,
+    pub frame: usize,
+    pub timer: Timer,
+}
+
+fn animate_eq_textures(
+    time: Res<Time>,
+    animated_materials: Res<Assets<EqAnimatedMaterial>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut query: Query<&mut EqAnimatedTexture>,
+) {
+    for mut animated in query.iter_mut() {
+        let asset = match animated_materials.get(&animated.animated_material) {
+            Some(asset) if !asset.frames.is_empty() => asset,
+            _ => continue,
+        };
+
+        animated
+            .timer
+            .set_duration(Duration::from_millis(asset.frame_delay_ms as u64));
+        animated.timer.tick(time.delta());
+        if !animated.timer.just_finished() {
+            continue;
+        }
+
+        animated.frame = (animated.frame + 1) % asset.frames.len();
+        if let Some(material) = materials.get_mut(&animated.material) {
+            material.base_color_texture = Some(asset.frames[animated.frame].clone());
+        }
+    }
 }
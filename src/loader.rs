@@ -1,11 +1,11 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
 use bevy_asset::{AssetLoader, AssetPath, BoxedFuture, Handle, LoadContext, LoadedAsset};
 use bevy_ecs::prelude::World;
 use bevy_hierarchy::BuildWorldChildren;
-use bevy_math::Vec3;
-use bevy_pbr::prelude::{PbrBundle, StandardMaterial};
+use bevy_math::{EulerRot, Mat4, Quat, Vec3};
+use bevy_pbr::prelude::{AlphaMode, PbrBundle, StandardMaterial};
 use bevy_render::{
     mesh::{Indices, Mesh, VertexAttributeValues},
     prelude::{Image, SpatialBundle},
@@ -16,16 +16,50 @@ use bevy_render::{
     texture::ImageSampler,
 };
 use bevy_scene::Scene;
+use bevy_tasks::{IoTaskPool, TaskPool};
+use bevy_time::Timer;
 use bevy_transform::prelude::Transform;
 
 use bevy_utils::default;
-use image::ImageFormat;
-use log::{debug, error, info};
+use image::{ImageFormat, RgbaImage};
+use log::{debug, error, info, warn};
 
-use super::{EqArchive, EqMesh, EqPrimitive, EqWld};
+use super::{
+    EqAnimatedMaterial, EqAnimatedTexture, EqAnimation, EqArchive, EqBone, EqBoneTrack, EqMesh,
+    EqNode, EqPrimitive, EqSkeleton, EqWld,
+};
+
+/// Decodes Everquest archives and builds the Bevy assets they contain.
+///
+/// Texture decoding for a single archive is fanned out across `pool` so a
+/// `.s3d` with hundreds of bitmaps doesn't monopolize one asset task.
+pub struct EqAssetsLoader {
+    pool: TaskPool,
+}
 
-#[derive(Default)]
-pub struct EqAssetsLoader;
+impl EqAssetsLoader {
+    /// Builds a loader that fans texture decoding out across `pool`,
+    /// instead of spinning up a dedicated one. Pass `IoTaskPool::get().0.clone()`
+    /// (or another resource the app already shares) so archive loading
+    /// doesn't duplicate Bevy's own task pools.
+    pub fn new(pool: TaskPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl Default for EqAssetsLoader {
+    fn default() -> Self {
+        // `init_asset_loader` can construct this before `TaskPoolPlugin` has
+        // installed the global `IoTaskPool` (or in a headless/test app that
+        // never adds it at all), and `IoTaskPool::get()` panics in that
+        // case. Fall back to a dedicated pool rather than trusting plugin
+        // registration order.
+        let pool = IoTaskPool::try_get()
+            .map(|pool| pool.0.clone())
+            .unwrap_or_else(TaskPool::new);
+        Self::new(pool)
+    }
+}
 
 impl AssetLoader for EqAssetsLoader {
     fn load<'a>(
@@ -33,7 +67,7 @@ impl AssetLoader for EqAssetsLoader {
         bytes: &'a [u8],
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<()>> {
-        Box::pin(async move { Ok(load_eq_archive(bytes, load_context)) })
+        Box::pin(async move { load_eq_archive(bytes, load_context, &self.pool) })
     }
 
     fn extensions(&self) -> &[&str] {
@@ -41,22 +75,18 @@ impl AssetLoader for EqAssetsLoader {
     }
 }
 
-fn load_eq_archive(bytes: &[u8], load_context: &mut LoadContext) {
-    let mut named_sources = HashMap::new();
-    let mut named_wlds = HashMap::new();
+fn load_eq_archive(bytes: &[u8], load_context: &mut LoadContext, pool: &TaskPool) -> Result<()> {
+    let mut texture_entries = vec![];
+    let mut wld_entries = vec![];
     for (name, asset) in eq_archive::load(bytes)
-        .expect("Failed to load archive")
+        .context("Failed to load archive")?
         .files()
     {
         match name.splitn(2, ".").last() {
-            Some("bmp") => {
-                let source = load_bmp(&name[..], &asset[..], load_context);
-                named_sources.insert(name, source);
-            }
-            Some("wld") => {
-                let wld = load_wld(&name[..], &asset[..], load_context);
-                named_wlds.insert(name, wld);
+            Some("bmp") | Some("dds") | Some("png") | Some("tga") => {
+                texture_entries.push((name, asset))
             }
+            Some("wld") => wld_entries.push((name, asset)),
             Some(_) => {
                 error!("Unknown file type, ignoring: {}", name);
             }
@@ -66,27 +96,197 @@ fn load_eq_archive(bytes: &[u8], load_context: &mut LoadContext) {
         }
     }
 
+    // Decode every texture concurrently on the shared task pool, then join
+    // before touching `load_context` (which isn't `Send`).
+    let decoded = pool.scope(|scope| {
+        for (name, asset) in &texture_entries {
+            scope.spawn(async move { (name.clone(), decode_texture(name, &asset[..])) });
+        }
+    });
+
+    let mut named_sources = HashMap::new();
+    // Raw pixels for bmp-sourced textures only, kept around so `load_wld`
+    // can build a color-keyed copy for materials it resolves as `Mask`
+    // (see `resolve_base_color_texture`) without re-decoding the bitmap.
+    let mut bmp_images: HashMap<String, RgbaImage> = HashMap::new();
+    for (name, texture) in decoded {
+        match texture {
+            Ok(texture) => {
+                if let TextureData::Rgba(image) = &texture {
+                    if name.splitn(2, ".").last() == Some("bmp") {
+                        bmp_images.insert(name.clone(), image.clone());
+                    }
+                }
+                let source = set_texture_asset(&name, texture, load_context);
+                named_sources.insert(name, source);
+            }
+            Err(err) => warn!("Failed to decode texture {}: {:#}", name, err),
+        }
+    }
+
+    let mut named_wlds = HashMap::new();
+    for (name, asset) in wld_entries {
+        match load_wld(&name[..], &asset[..], load_context, &bmp_images) {
+            Ok(wld) => {
+                named_wlds.insert(name, wld);
+            }
+            Err(err) => warn!("Failed to load wld {}: {:#}", name, err),
+        }
+    }
+
     load_context.set_default_asset(LoadedAsset::new(EqArchive {
         named_sources,
         named_wlds,
     }));
+    Ok(())
+}
+
+/// A decoded texture, either plain RGBA pixels or GPU-native compressed
+/// blocks that should be uploaded as-is.
+enum TextureData {
+    Rgba(RgbaImage),
+    Compressed {
+        data: Vec<u8>,
+        format: TextureFormat,
+        width: u32,
+        height: u32,
+    },
+}
+
+fn decode_texture(name: &str, bytes: &[u8]) -> Result<TextureData> {
+    match name.splitn(2, ".").last() {
+        Some("dds") => decode_dds(bytes),
+        Some("png") => Ok(TextureData::Rgba(decode_with_format(
+            bytes,
+            ImageFormat::Png,
+        )?)),
+        Some("tga") => Ok(TextureData::Rgba(decode_with_format(
+            bytes,
+            ImageFormat::Tga,
+        )?)),
+        _ => Ok(TextureData::Rgba(decode_with_format(bytes, ImageFormat::Bmp)?)),
+    }
+}
+
+fn decode_with_format(bytes: &[u8], format: ImageFormat) -> Result<RgbaImage> {
+    Ok(image::load_from_memory_with_format(bytes, format)
+        .context("Failed to decode texture")?
+        .into_rgba8())
+}
+
+/// Trilogy/SoF-era `.eqg` archives store textures as DXT-compressed DDS.
+/// Feed the compressed blocks straight through to the GPU instead of
+/// inflating them to RGBA, keeping the real `TextureFormat` intact.
+fn decode_dds(bytes: &[u8]) -> Result<TextureData> {
+    let dds = ddsfile::Dds::read(bytes).context("Failed to parse DDS")?;
+    let width = dds.get_width();
+    let height = dds.get_height();
+    let format = match dds.get_d3d_format() {
+        Some(ddsfile::D3DFormat::DXT1) => TextureFormat::Bc1RgbaUnormSrgb,
+        Some(ddsfile::D3DFormat::DXT3) => TextureFormat::Bc2RgbaUnormSrgb,
+        Some(ddsfile::D3DFormat::DXT5) => TextureFormat::Bc3RgbaUnormSrgb,
+        other => bail!("Unsupported DDS pixel format: {:?}", other),
+    };
+    let data = dds.get_data(0).context("DDS has no surface data")?.to_vec();
+
+    Ok(TextureData::Compressed {
+        data,
+        format,
+        width,
+        height,
+    })
+}
+
+/// EQ's 8-bit BMPs use the first palette entry (the color of the top-left
+/// texel) as a transparency key. Rewrite every texel matching it to alpha 0
+/// so `AlphaMode::Mask` cuts the surface out correctly. Only called on the
+/// copy a `Mask`-mode material actually uses (see
+/// `resolve_base_color_texture`): the same bitmap shared by a `Blend`
+/// surface (scrolling water, lava…) is frequently one flat color over most
+/// of its area, so keying it unconditionally would zero out alpha across
+/// the whole surface instead of cutting a hole in it.
+fn key_out_transparent(image: &mut RgbaImage) {
+    let key = match image.pixels().next() {
+        Some(pixel) => pixel.0,
+        None => return,
+    };
+    for pixel in image.pixels_mut() {
+        if pixel.0[..3] == key[..3] {
+            pixel.0[3] = 0;
+        }
+    }
 }
 
-fn load_bmp(name: &str, bytes: &[u8], load_context: &mut LoadContext) -> Handle<Image> {
-    let image = image::load_from_memory_with_format(bytes, ImageFormat::Bmp)
-        .expect("Failed to load bitmap")
-        .into_rgba8();
-    let format = TextureFormat::Rgba8UnormSrgb;
+fn set_texture_asset(
+    name: &str,
+    texture: TextureData,
+    load_context: &mut LoadContext,
+) -> Handle<Image> {
+    let (data, format, width, height) = match texture {
+        TextureData::Rgba(image) => {
+            let (width, height) = (image.width(), image.height());
+            (
+                image.into_raw(),
+                TextureFormat::Rgba8UnormSrgb,
+                width,
+                height,
+            )
+        }
+        TextureData::Compressed {
+            data,
+            format,
+            width,
+            height,
+        } => (data, format, width, height),
+    };
+    set_image_asset(&texture_label(name), data, format, width, height, load_context)
+}
+
+/// Resolves the texture a material's `base_color_texture` should point at.
+/// `Mask`-mode materials get a color-keyed copy of their bmp (see
+/// `key_out_transparent`); every other alpha mode uses the texture exactly
+/// as decoded, since the same bitmap is often shared with `Blend`/`Opaque`
+/// materials that keying would break.
+fn resolve_base_color_texture(
+    texture_name: &str,
+    alpha_mode: AlphaMode,
+    bmp_images: &HashMap<String, RgbaImage>,
+    load_context: &mut LoadContext,
+) -> Handle<Image> {
+    if let (AlphaMode::Mask(_), Some(image)) = (alpha_mode, bmp_images.get(texture_name)) {
+        let mut keyed = image.clone();
+        key_out_transparent(&mut keyed);
+        let (width, height) = keyed.dimensions();
+        return set_image_asset(
+            &masked_texture_label(texture_name),
+            keyed.into_raw(),
+            TextureFormat::Rgba8UnormSrgb,
+            width,
+            height,
+            load_context,
+        );
+    }
+
+    let label = texture_label(texture_name);
+    load_context.get_handle(AssetPath::new_ref(load_context.path(), Some(&label)))
+}
+
+fn set_image_asset(
+    label: &str,
+    data: Vec<u8>,
+    format: TextureFormat,
+    width: u32,
+    height: u32,
+    load_context: &mut LoadContext,
+) -> Handle<Image> {
     let size = Extent3d {
-        width: image.width(),
-        height: image.height(),
+        width,
+        height,
         depth_or_array_layers: 1,
     };
-    let data = image.into_raw();
-    let label = texture_label(name);
 
     load_context.set_labeled_asset(
-        &label,
+        label,
         LoadedAsset::new(Image {
             data,
             texture_descriptor: TextureDescriptor {
@@ -106,19 +306,50 @@ fn load_bmp(name: &str, bytes: &[u8], load_context: &mut LoadContext) -> Handle<
             ..Default::default()
         }),
     );
-    load_context.get_handle(AssetPath::new_ref(load_context.path(), Some(&label)))
+    load_context.get_handle(AssetPath::new_ref(load_context.path(), Some(label)))
 }
 
-fn load_wld(wld_name: &str, bytes: &[u8], load_context: &mut LoadContext) -> Handle<EqWld> {
+fn load_wld(
+    wld_name: &str,
+    bytes: &[u8],
+    load_context: &mut LoadContext,
+    bmp_images: &HashMap<String, RgbaImage>,
+) -> Result<Handle<EqWld>> {
     info!("Loading wld file: {}", wld_name);
-    let wld = eq_wld::load(bytes).expect(&format!("Failed to load wld: {}", wld_name));
+    let wld = eq_wld::load(bytes).with_context(|| format!("Failed to load wld: {}", wld_name))?;
+
+    // Load the skeleton and animation clips, if this is a mob/character WLD.
+    let skeleton = wld.skeleton();
+    let skeleton_handle = skeleton
+        .as_ref()
+        .map(|skeleton| load_skeleton(wld_name, skeleton, load_context))
+        .transpose()?;
+
+    let mut named_animations = HashMap::new();
+    if skeleton.is_some() {
+        for animation in wld.animations() {
+            let code = animation.code().to_string();
+            let handle = load_animation(wld_name, &animation, load_context);
+            named_animations.insert(code, handle);
+        }
+    }
 
     // Load materials
     let mut materials = vec![];
     let mut named_materials: HashMap<String, Handle<_>> = HashMap::new();
+    let mut alpha_modes: HashMap<String, AlphaMode> = HashMap::new();
+    let mut animated_materials: HashMap<String, Handle<EqAnimatedMaterial>> = HashMap::new();
     for material in wld.materials() {
         let label = material_label(wld_name, material.name().unwrap_or(""));
 
+        let alpha_mode = match alpha_mode_for(material.render_mode()) {
+            Some(mode) => mode,
+            None => {
+                debug!("{} is invisible, skipping", label);
+                continue;
+            }
+        };
+
         let texture = match material.base_color_texture() {
             Some(t) => t,
             None => {
@@ -134,97 +365,143 @@ fn load_wld(wld_name: &str, bytes: &[u8], load_context: &mut LoadContext) -> Han
             }
         };
 
-        let material_handle = load_material(&label, texture_name, load_context);
+        let texture_handle =
+            resolve_base_color_texture(&texture_name, alpha_mode, bmp_images, load_context);
+        let material_handle = load_material(&label, texture_handle, alpha_mode, load_context);
         if let Some(name) = material.name() {
             materials.push(material_handle.clone());
             named_materials.insert(name.to_string(), material_handle.clone());
+            alpha_modes.insert(name.to_string(), alpha_mode);
+
+            if let Some(animated) = load_animated_material(&label, &texture, load_context) {
+                animated_materials.insert(name.to_string(), animated);
+            }
         }
     }
 
-    // Load meshes
+    // Load meshes. Keyed by their position in `wld.meshes()`, not by name:
+    // mesh names are frequently blank or repeated (unlike material/texture
+    // names), and a name-keyed map would silently drop every mesh but the
+    // last sharing a name instead of spawning each one's own geometry.
     let mut meshes = vec![];
     let mut named_meshes = HashMap::new();
-    let mut world = World::default();
-
-    world
-        .spawn(SpatialBundle::default())
-        .with_children(|parent| {
-            for mesh in wld.meshes() {
-                let mut primitives = vec![];
-                let (x, y, z) = mesh.center();
-                parent
-                    .spawn(SpatialBundle {
-                        transform: Transform::from_translation(Vec3::new(x, y, z)),
-                        ..default()
-                    })
-                    .with_children(|parent| {
-                        for primitive in mesh.primitives() {
-                            let mut bevy_mesh = Mesh::new(PrimitiveTopology::TriangleList);
-
-                            // Set vertex positions
-                            bevy_mesh.insert_attribute(
-                                Mesh::ATTRIBUTE_POSITION,
-                                VertexAttributeValues::Float32x3(primitive.positions()),
-                            );
-
-                            // Set normals
-                            bevy_mesh.insert_attribute(
-                                Mesh::ATTRIBUTE_NORMAL,
-                                VertexAttributeValues::Float32x3(primitive.normals()),
-                            );
-
-                            // Set texture coordinates
-                            let texture_coordinates = primitive.texture_coordinates();
-                            if texture_coordinates.len() > 0 {
-                                bevy_mesh.insert_attribute(
-                                    Mesh::ATTRIBUTE_UV_0,
-                                    VertexAttributeValues::Float32x2(texture_coordinates),
-                                );
-                            }
-
-                            // Set vertex indices
-                            bevy_mesh.set_indices(Some(Indices::U32(primitive.indices())));
-
-                            let label = primitive_label(
-                                wld_name,
-                                mesh.name().unwrap_or(""),
-                                primitive.index(),
-                            );
-                            load_context.set_labeled_asset(&label, LoadedAsset::new(bevy_mesh));
-                            let mesh_handle: Handle<Mesh> = load_context
-                                .get_handle(AssetPath::new_ref(load_context.path(), Some(&label)));
-                            let material_handle = match named_materials
-                                .get(primitive.material().name().unwrap())
-                                .cloned()
-                            {
-                                Some(material) => material,
-                                None => {
-                                    debug!("Could not find {:?}", primitive.material().name());
-                                    continue;
-                                }
-                            };
-
-                            parent.spawn(PbrBundle {
-                                mesh: mesh_handle.clone(),
-                                material: material_handle.clone(),
-                                ..Default::default()
-                            });
-
-                            primitives.push(EqPrimitive {
-                                mesh: mesh_handle.clone(),
-                                material: material_handle.clone(),
-                            })
-                        }
-                    });
+    let mut mesh_primitives = vec![];
+    let mut mesh_index_by_name: HashMap<String, usize> = HashMap::new();
+
+    for mesh in wld.meshes() {
+        // Asset labels are built from the mesh name, so a name already used by
+        // an earlier mesh gets that mesh's position appended to keep the two
+        // meshes' labeled assets apart.
+        let mesh_name = mesh.name().unwrap_or("").to_string();
+        let label_name = if mesh_index_by_name.contains_key(&mesh_name) {
+            format!("{}@{}", mesh_name, meshes.len())
+        } else {
+            mesh_name.clone()
+        };
+        let mut primitives = vec![];
+        for primitive in mesh.primitives() {
+            let mut bevy_mesh = Mesh::new(PrimitiveTopology::TriangleList);
+
+            // Set vertex positions
+            bevy_mesh.insert_attribute(
+                Mesh::ATTRIBUTE_POSITION,
+                VertexAttributeValues::Float32x3(primitive.positions()),
+            );
+
+            // Set normals
+            bevy_mesh.insert_attribute(
+                Mesh::ATTRIBUTE_NORMAL,
+                VertexAttributeValues::Float32x3(primitive.normals()),
+            );
+
+            // Set texture coordinates
+            let texture_coordinates = primitive.texture_coordinates();
+            if texture_coordinates.len() > 0 {
+                bevy_mesh.insert_attribute(
+                    Mesh::ATTRIBUTE_UV_0,
+                    VertexAttributeValues::Float32x2(texture_coordinates),
+                );
+            }
 
-                let label = mesh_label(wld_name, mesh.name().unwrap_or(""));
-                load_context.set_labeled_asset(&label, LoadedAsset::new(EqMesh { primitives }));
-                let eq_mesh_handle: Handle<EqMesh> =
-                    load_context.get_handle(AssetPath::new_ref(load_context.path(), Some(&label)));
-                named_meshes.insert(label, eq_mesh_handle.clone());
-                meshes.push(eq_mesh_handle.clone());
+            // Set vertex indices
+            bevy_mesh.set_indices(Some(Indices::U32(primitive.indices())));
+
+            // Set joint indices/weights, if this mesh is skinned.
+            match (primitive.joint_indices(), primitive.joint_weights()) {
+                (Some(indices), Some(weights)) if skeleton.is_some() => {
+                    bevy_mesh.insert_attribute(
+                        Mesh::ATTRIBUTE_JOINT_INDEX,
+                        VertexAttributeValues::Uint16x4(indices),
+                    );
+                    bevy_mesh.insert_attribute(
+                        Mesh::ATTRIBUTE_JOINT_WEIGHT,
+                        VertexAttributeValues::Float32x4(weights),
+                    );
+                }
+                (Some(_), Some(_)) => {
+                    warn!(
+                        "{}: mesh {:?} references joints but has no skeleton, dropping joint attributes",
+                        wld_name,
+                        mesh.name()
+                    );
+                }
+                _ => {}
             }
-        });
+
+            let label = primitive_label(wld_name, &label_name, primitive.index());
+            load_context.set_labeled_asset(&label, LoadedAsset::new(bevy_mesh));
+            let mesh_handle: Handle<Mesh> =
+                load_context.get_handle(AssetPath::new_ref(load_context.path(), Some(&label)));
+
+            let material_name = match primitive.material().name() {
+                Some(name) => name,
+                None => {
+                    debug!("Primitive references an unnamed material, skipping");
+                    continue;
+                }
+            };
+
+            let material_handle = match named_materials.get(material_name).cloned() {
+                Some(material) => material,
+                None => {
+                    debug!("Could not find {:?}", material_name);
+                    continue;
+                }
+            };
+
+            let alpha_mode = alpha_modes
+                .get(material_name)
+                .copied()
+                .unwrap_or(AlphaMode::Opaque);
+            let animated_material = animated_materials.get(material_name).cloned();
+
+            primitives.push(EqPrimitive {
+                mesh: mesh_handle.clone(),
+                material: material_handle.clone(),
+                alpha_mode,
+                animated_material,
+            })
+        }
+
+        let label = mesh_label(wld_name, &label_name);
+        load_context.set_labeled_asset(
+            &label,
+            LoadedAsset::new(EqMesh {
+                primitives: primitives.clone(),
+            }),
+        );
+        let eq_mesh_handle: Handle<EqMesh> =
+            load_context.get_handle(AssetPath::new_ref(load_context.path(), Some(&label)));
+        named_meshes.insert(label, eq_mesh_handle.clone());
+        mesh_index_by_name.insert(mesh_name, meshes.len());
+        meshes.push(eq_mesh_handle);
+        mesh_primitives.push(primitives);
+    }
+
+    // Build the placement hierarchy (object-instance and region/BSP records
+    // that position static meshes) and spawn it as the zone's scene, instead
+    // of merging everything into a single translated blob.
+    let (world, root) = build_scene(&wld, &mesh_index_by_name, &meshes, &mesh_primitives);
 
     let label = wld_label(wld_name);
     load_context.set_labeled_asset(
@@ -238,27 +515,156 @@ fn load_wld(wld_name: &str, bytes: &[u8], load_context: &mut LoadContext) -> Han
             named_meshes,
             materials,
             named_materials,
+            root,
+            skeleton: skeleton_handle,
+            animations: named_animations,
         }),
     );
     info!("Loaded: {}", label);
-    load_context.get_handle(AssetPath::new_ref(load_context.path(), Some(&label)))
+    Ok(load_context.get_handle(AssetPath::new_ref(load_context.path(), Some(&label))))
+}
+
+/// Walks the WLD's placement records, building both the `EqNode` tree
+/// (for code that wants to inspect placements directly) and the matching
+/// Bevy `World` hierarchy that becomes the zone's `Scene`.
+fn build_scene(
+    wld: &eq_wld::Wld,
+    mesh_index_by_name: &HashMap<String, usize>,
+    mesh_handles: &[Handle<EqMesh>],
+    mesh_primitives: &[Vec<EqPrimitive>],
+) -> (World, EqNode) {
+    let mut world = World::default();
+    let mut children = vec![];
+    let mut placed_mesh_indices = HashSet::new();
+
+    world
+        .spawn(SpatialBundle::default())
+        .with_children(|parent| {
+            for placement in wld.placements() {
+                let transform = placement_transform(&placement);
+                let mesh_name = placement.mesh_name();
+
+                let mesh_index = mesh_name.and_then(|name| mesh_index_by_name.get(name).copied());
+                if mesh_index.is_none() {
+                    debug!("Placement references unknown mesh {:?}", mesh_name);
+                } else if let Some(index) = mesh_index {
+                    placed_mesh_indices.insert(index);
+                }
+
+                let mesh_handle = mesh_index.map(|index| &mesh_handles[index]);
+                let primitives = mesh_index.map(|index| &mesh_primitives[index]);
+                spawn_mesh_entity(parent, transform, primitives);
+
+                children.push(EqNode {
+                    children: vec![],
+                    mesh: mesh_handle.cloned(),
+                    transform,
+                });
+            }
+
+            // Zone WLDs keep the bulk of their static geometry (walls,
+            // terrain, buildings…) as bare meshes with no placement record
+            // at all — only discrete props get an actor-instance placement.
+            // Anything left unclaimed after the pass above still needs to be
+            // spawned, centered on itself like the old flattened scene did,
+            // or the zone's base geometry would silently render as nothing.
+            let mut unplaced = 0;
+            for (index, mesh) in wld.meshes().enumerate() {
+                if placed_mesh_indices.contains(&index) {
+                    continue;
+                }
+                unplaced += 1;
+
+                let mesh_handle = mesh_handles.get(index);
+                let primitives = mesh_primitives.get(index);
+                let (x, y, z) = mesh.center();
+                let transform = Transform::from_translation(Vec3::new(x, y, z));
+
+                spawn_mesh_entity(parent, transform, primitives);
+
+                children.push(EqNode {
+                    children: vec![],
+                    mesh: mesh_handle.cloned(),
+                    transform,
+                });
+            }
+            debug!(
+                "Scene has {} placed and {} unplaced meshes",
+                placed_mesh_indices.len(),
+                unplaced
+            );
+        });
+
+    (
+        world,
+        EqNode {
+            children,
+            mesh: None,
+            transform: Transform::default(),
+        },
+    )
+}
+
+/// Spawns a single mesh's primitives (and their animated-texture components,
+/// if any) as children of `parent` at `transform`. Shared by placement
+/// records and the unplaced-mesh fallback in [`build_scene`].
+fn spawn_mesh_entity(
+    parent: &mut bevy_hierarchy::WorldChildBuilder,
+    transform: Transform,
+    primitives: Option<&Vec<EqPrimitive>>,
+) {
+    parent
+        .spawn(SpatialBundle {
+            transform,
+            ..default()
+        })
+        .with_children(|parent| {
+            for primitive in primitives.into_iter().flatten() {
+                let mut entity = parent.spawn(PbrBundle {
+                    mesh: primitive.mesh.clone(),
+                    material: primitive.material.clone(),
+                    ..Default::default()
+                });
+                if let Some(animated_material) = primitive.animated_material.clone() {
+                    entity.insert(EqAnimatedTexture {
+                        material: primitive.material.clone(),
+                        animated_material,
+                        frame: 0,
+                        timer: Timer::from_seconds(0.1, true),
+                    });
+                }
+            }
+        });
+}
+
+fn placement_transform(placement: &eq_wld::Placement) -> Transform {
+    let (x, y, z) = placement.location();
+    let (rx, ry, rz) = placement.rotation();
+    let scale = placement.scale();
+
+    Transform {
+        translation: Vec3::new(x, y, z),
+        rotation: Quat::from_euler(
+            EulerRot::XYZ,
+            rx.to_radians(),
+            ry.to_radians(),
+            rz.to_radians(),
+        ),
+        scale: Vec3::splat(scale),
+    }
 }
 
 fn load_material(
     label: &str,
-    texture_name: String,
+    texture_handle: Handle<Image>,
+    alpha_mode: AlphaMode,
     load_context: &mut LoadContext,
 ) -> Handle<StandardMaterial> {
-    let texture_label = texture_label(&texture_name);
-    let texture_handle = load_context.get_handle(AssetPath::new_ref(
-        load_context.path(),
-        Some(&texture_label),
-    ));
-
     load_context.set_labeled_asset(
         &label,
         LoadedAsset::new(StandardMaterial {
             base_color_texture: Some(texture_handle),
+            alpha_mode,
             unlit: true,
             ..Default::default()
         }),
@@ -266,10 +672,178 @@ fn load_material(
     load_context.get_handle(AssetPath::new_ref(load_context.path(), Some(&label)))
 }
 
+/// Builds an `EqAnimatedMaterial` out of a material's multi-frame bitmap
+/// list (scrolling water, waterfalls, lava…). Returns `None` for ordinary
+/// single-frame textures.
+fn load_animated_material(
+    material_label: &str,
+    texture: &eq_wld::Texture,
+    load_context: &mut LoadContext,
+) -> Option<Handle<EqAnimatedMaterial>> {
+    let frame_names = texture.frames();
+    if frame_names.len() <= 1 {
+        return None;
+    }
+
+    let frames = frame_names
+        .iter()
+        .map(|name| {
+            let label = texture_label(name);
+            load_context.get_handle(AssetPath::new_ref(load_context.path(), Some(&label)))
+        })
+        .collect();
+
+    let label = format!("{}/Animated", material_label);
+    load_context.set_labeled_asset(
+        &label,
+        LoadedAsset::new(EqAnimatedMaterial {
+            frames,
+            frame_delay_ms: texture.frame_delay_ms().unwrap_or(0),
+        }),
+    );
+    Some(load_context.get_handle(AssetPath::new_ref(load_context.path(), Some(&label))))
+}
+
+/// Maps a WLD material's render/shader flags onto Bevy's `AlphaMode`.
+/// Returns `None` for the fully invisible "transparent" mode used by
+/// boundary polygons, which callers should skip entirely.
+fn alpha_mode_for(mode: eq_wld::RenderMode) -> Option<AlphaMode> {
+    use eq_wld::RenderMode::*;
+
+    match mode {
+        Normal => Some(AlphaMode::Opaque),
+        Masked => Some(AlphaMode::Mask(0.5)),
+        Transparent25 | Transparent50 | Transparent75 | Additive => Some(AlphaMode::Blend),
+        Transparent => None,
+    }
+}
+
+/// Builds the bone tree and inverse-bind matrices from a WLD's skeleton
+/// track hierarchy. Assumes bones are ordered parent-before-child, as the
+/// skeleton fragment set does; a bone whose `parent_index` doesn't point at
+/// an already-processed bone is rejected rather than indexed into, since a
+/// malformed file is otherwise indistinguishable from an out-of-bounds panic.
+fn load_skeleton(
+    wld_name: &str,
+    skeleton: &eq_wld::Skeleton,
+    load_context: &mut LoadContext,
+) -> Result<Handle<EqSkeleton>> {
+    let mut bones = vec![];
+    let mut bind_matrices: Vec<Mat4> = vec![];
+
+    for bone in skeleton.bones() {
+        let local_transform = track_transform(bone.translation(), bone.rotation(), bone.scale());
+        let local_matrix = local_transform.compute_matrix();
+        let parent = bone.parent_index();
+        let bind_matrix = chain_bind_matrix(&bind_matrices, parent, local_matrix).with_context(
+            || {
+                format!(
+                    "Bone {:?} in {} has out-of-range parent index {:?}",
+                    bone.name(),
+                    wld_name,
+                    parent
+                )
+            },
+        )?;
+
+        bind_matrices.push(bind_matrix);
+        bones.push(EqBone {
+            name: bone.name().unwrap_or("").to_string(),
+            parent,
+            local_transform,
+            inverse_bind_matrix: bind_matrix.inverse(),
+        });
+    }
+
+    let label = skeleton_label(wld_name);
+    load_context.set_labeled_asset(&label, LoadedAsset::new(EqSkeleton { bones }));
+    Ok(load_context.get_handle(AssetPath::new_ref(load_context.path(), Some(&label))))
+}
+
+/// Chains a bone's local matrix onto its parent's bind matrix, or returns it
+/// as-is for a root bone. `bind_matrices` holds one entry per bone processed
+/// so far, in order, so `parent` must index a bone earlier in that list;
+/// anything else (out of range, or pointing forward/at itself) is an error
+/// rather than a panic.
+fn chain_bind_matrix(
+    bind_matrices: &[Mat4],
+    parent: Option<usize>,
+    local_matrix: Mat4,
+) -> Result<Mat4> {
+    match parent {
+        Some(parent) => {
+            let parent_matrix = bind_matrices
+                .get(parent)
+                .with_context(|| format!("parent index {} is out of range", parent))?;
+            Ok(*parent_matrix * local_matrix)
+        }
+        None => Ok(local_matrix),
+    }
+}
+
+fn load_animation(
+    wld_name: &str,
+    animation: &eq_wld::Animation,
+    load_context: &mut LoadContext,
+) -> Handle<EqAnimation> {
+    let code = animation.code().to_string();
+    let tracks = animation
+        .bone_frames()
+        .into_iter()
+        .map(|(bone, frames)| EqBoneTrack {
+            bone,
+            frames: frames
+                .into_iter()
+                .map(|(translation, rotation, scale)| track_transform(translation, rotation, scale))
+                .collect(),
+        })
+        .collect();
+
+    let label = animation_label(wld_name, &code);
+    load_context.set_labeled_asset(
+        &label,
+        LoadedAsset::new(EqAnimation {
+            code,
+            frame_delay_ms: animation.frame_delay_ms(),
+            tracks,
+        }),
+    );
+    load_context.get_handle(AssetPath::new_ref(load_context.path(), Some(&label)))
+}
+
+fn track_transform(
+    translation: (f32, f32, f32),
+    rotation: (f32, f32, f32),
+    scale: (f32, f32, f32),
+) -> Transform {
+    let (x, y, z) = translation;
+    let (rx, ry, rz) = rotation;
+    let (sx, sy, sz) = scale;
+
+    Transform {
+        translation: Vec3::new(x, y, z),
+        rotation: Quat::from_euler(
+            EulerRot::XYZ,
+            rx.to_radians(),
+            ry.to_radians(),
+            rz.to_radians(),
+        ),
+        scale: Vec3::new(sx, sy, sz),
+    }
+}
+
 fn wld_label(wld_name: &str) -> String {
     format!("Wld[{}]", wld_name)
 }
 
+fn skeleton_label(wld_name: &str) -> String {
+    format!("{}/Skeleton", wld_label(wld_name))
+}
+
+fn animation_label(wld_name: &str, code: &str) -> String {
+    format!("{}/Animation[{}]", wld_label(wld_name), code)
+}
+
 fn material_label(wld_name: &str, name: &str) -> String {
     format!("{}/Material[{}]", wld_label(wld_name), name)
 }
@@ -278,6 +852,10 @@ fn texture_label(name: &str) -> String {
     format!("Texture[{}]", name)
 }
 
+fn masked_texture_label(name: &str) -> String {
+    format!("{}/Masked", texture_label(name))
+}
+
 fn mesh_label(wld_name: &str, name: &str) -> String {
     format!("{}/Mesh[{}]", wld_label(wld_name), name)
 }
@@ -289,3 +867,76 @@ fn primitive_label(wld_name: &str, mesh_name: &str, primitive_index: usize) -> S
         primitive_index
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    #[test]
+    fn alpha_mode_for_maps_each_render_mode() {
+        use eq_wld::RenderMode::*;
+
+        assert_eq!(alpha_mode_for(Normal), Some(AlphaMode::Opaque));
+        assert_eq!(alpha_mode_for(Masked), Some(AlphaMode::Mask(0.5)));
+        assert_eq!(alpha_mode_for(Transparent25), Some(AlphaMode::Blend));
+        assert_eq!(alpha_mode_for(Transparent50), Some(AlphaMode::Blend));
+        assert_eq!(alpha_mode_for(Transparent75), Some(AlphaMode::Blend));
+        assert_eq!(alpha_mode_for(Additive), Some(AlphaMode::Blend));
+        assert_eq!(alpha_mode_for(Transparent), None);
+    }
+
+    #[test]
+    fn key_out_transparent_clears_pixels_matching_the_corner_key() {
+        let mut image = RgbaImage::from_fn(2, 2, |x, y| {
+            if (x, y) == (1, 1) {
+                Rgba([10, 20, 30, 255])
+            } else {
+                Rgba([0, 0, 0, 255])
+            }
+        });
+
+        key_out_transparent(&mut image);
+
+        assert_eq!(*image.get_pixel(0, 0), Rgba([0, 0, 0, 0]));
+        assert_eq!(*image.get_pixel(1, 1), Rgba([10, 20, 30, 255]));
+    }
+
+    #[test]
+    fn key_out_transparent_is_a_noop_on_an_empty_image() {
+        let mut image = RgbaImage::new(0, 0);
+        key_out_transparent(&mut image);
+        assert_eq!(image.dimensions(), (0, 0));
+    }
+
+    #[test]
+    fn chain_bind_matrix_root_bone_is_its_own_local_matrix() {
+        let local = Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0));
+        let bind = chain_bind_matrix(&[], None, local).unwrap();
+        assert_eq!(bind, local);
+    }
+
+    #[test]
+    fn chain_bind_matrix_chains_onto_parent() {
+        let parent_bind = Mat4::from_translation(Vec3::new(1.0, 0.0, 0.0));
+        let local = Mat4::from_translation(Vec3::new(0.0, 1.0, 0.0));
+        let bind = chain_bind_matrix(&[parent_bind], Some(0), local).unwrap();
+        assert_eq!(bind, parent_bind * local);
+    }
+
+    #[test]
+    fn chain_bind_matrix_rejects_out_of_range_parent() {
+        let local = Mat4::IDENTITY;
+        assert!(chain_bind_matrix(&[], Some(0), local).is_err());
+    }
+
+    #[test]
+    fn chain_bind_matrix_rejects_forward_reference() {
+        // A parent index pointing at a bone later in bone order hasn't been
+        // pushed into `bind_matrices` yet, so it must be rejected the same
+        // way as a genuinely out-of-range index.
+        let processed_so_far = [Mat4::IDENTITY];
+        let local = Mat4::IDENTITY;
+        assert!(chain_bind_matrix(&processed_so_far, Some(1), local).is_err());
+    }
+}